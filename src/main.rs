@@ -1,4 +1,5 @@
 use chrono::{ DateTime, Duration, TimeZone, Utc };
+use clap::Parser;
 use colored::Colorize;
 use dirs::home_dir;
 use reqwest::blocking::get;
@@ -19,6 +20,26 @@ struct Config {
     showdate: bool,
     timeformat: String,
     use_colors: bool,
+    autolocate: bool,
+    autolocate_interval: i64,
+    city_id: Option<u64>,
+    zipcode: Option<String>,
+    country_code: Option<String>,
+    coordinates: Option<[f64; 2]>,
+    show_forecast: bool,
+    output_format: String,
+    lang: String,
+    labels: Labels,
+}
+
+#[derive(Deserialize, Serialize, Default)]
+struct Labels {
+    temperature: Option<String>,
+    wind_speed: Option<String>,
+    sunrise: Option<String>,
+    sunset: Option<String>,
+    city: Option<String>,
+    date: Option<String>,
 }
 
 impl Default for Config {
@@ -33,23 +54,225 @@ impl Default for Config {
             showdate: false,
             timeformat: String::from("24"),
             use_colors: false,
+            autolocate: false,
+            autolocate_interval: 60,
+            city_id: None,
+            zipcode: None,
+            country_code: None,
+            coordinates: None,
+            show_forecast: false,
+            output_format: String::from("normal"),
+            lang: String::from("en"),
+            labels: Labels::default(),
         }
     }
 }
 
-fn read_config() -> Config {
-    let config_path = if cfg!(windows) {
+#[derive(Deserialize, Serialize)]
+struct LocationCache {
+    latitude: f64,
+    longitude: f64,
+    city: String,
+    fetched_at: i64,
+}
+
+fn config_dir() -> std::path::PathBuf {
+    if cfg!(windows) {
         let mut path = dirs::config_dir().expect("Failed to get config directory");
         path.push("thundery");
-        path.push("thundery.toml");
         path
     } else {
         let mut path = home_dir().expect("Failed to get home directory");
         path.push(".config");
         path.push("thundery");
-        path.push("thundery.toml");
         path
+    }
+}
+
+fn cache_path() -> std::path::PathBuf {
+    config_dir().join("thundery.cache.json")
+}
+
+// Queries a free IP-geolocation service for a rough fix; used when `autolocate` is enabled.
+// A short timeout keeps a black-holed connection from hanging `main` instead of falling back.
+fn fetch_autolocation() -> Option<(f64, f64, String)> {
+    let client = reqwest::blocking::Client
+        ::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .ok()?;
+    let response = client.get("https://ipapi.co/json/").send().ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let body: Value = response.json().ok()?;
+    let latitude = body["latitude"].as_f64()?;
+    let longitude = body["longitude"].as_f64()?;
+    let city = body["city"].as_str().unwrap_or("Unknown").to_string();
+    Some((latitude, longitude, city))
+}
+
+// Resolves coordinates for `autolocate`, reusing a cached fix within `autolocate_interval`
+// minutes and silently falling back to `None` (i.e. the configured city) on any failure.
+fn resolve_autolocation(config: &Config) -> Option<(f64, f64, String)> {
+    if !config.autolocate {
+        return None;
+    }
+
+    let path = cache_path();
+    if let Ok(cache_content) = fs::read_to_string(&path) {
+        if let Ok(cached) = serde_json::from_str::<LocationCache>(&cache_content) {
+            let age_seconds = Utc::now().timestamp() - cached.fetched_at;
+            if age_seconds < config.autolocate_interval * 60 {
+                return Some((cached.latitude, cached.longitude, cached.city));
+            }
+        }
+    }
+
+    let (latitude, longitude, city) = fetch_autolocation()?;
+
+    let cache = LocationCache {
+        latitude,
+        longitude,
+        city: city.clone(),
+        fetched_at: Utc::now().timestamp(),
     };
+    if let Ok(cache_string) = serde_json::to_string(&cache) {
+        if fs::create_dir_all(config_dir()).is_ok() {
+            let _ = fs::write(&path, cache_string);
+        }
+    }
+
+    Some((latitude, longitude, city))
+}
+
+// Builds the OpenWeatherMap location query parameter from whichever specifier is configured.
+// Exactly one of `city`, `city_id`, `zipcode` (with `country_code`), or `coordinates` must be set.
+fn build_location_query(config: &Config) -> Result<String, String> {
+    let populated = [
+        !config.city.is_empty(),
+        config.city_id.is_some(),
+        config.zipcode.is_some(),
+        config.coordinates.is_some(),
+    ]
+        .iter()
+        .filter(|is_set| **is_set)
+        .count();
+
+    if populated != 1 {
+        return Err(
+            format!(
+                "Expected exactly one location specifier, found {}. Configure exactly one of: city, city_id, zipcode (with country_code), or coordinates.",
+                populated
+            )
+        );
+    }
+
+    if let Some(city_id) = config.city_id {
+        return Ok(format!("id={}", city_id));
+    }
+    if let Some(zipcode) = &config.zipcode {
+        return Ok(match &config.country_code {
+            Some(country_code) => format!("zip={},{}", zipcode, country_code),
+            None => format!("zip={}", zipcode),
+        });
+    }
+    if let Some([lat, lon]) = config.coordinates {
+        return Ok(format!("lat={}&lon={}", lat, lon));
+    }
+    Ok(format!("q={}", config.city))
+}
+
+// Fetches the first forecast entry (roughly 3 hours out) for the trend indicator.
+fn fetch_forecast_temp(location_query: &str, config: &Config) -> Option<f64> {
+    let url = format!(
+        "https://api.openweathermap.org/data/2.5/forecast?{}&units={}&APPID={}",
+        location_query,
+        config.units,
+        config.api_key
+    );
+
+    let response = get(&url).ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let forecast_data: Value = response.json().ok()?;
+    forecast_data["list"][0]["main"]["temp"].as_f64()
+}
+
+#[derive(Serialize)]
+struct WeatherOutput {
+    city: String,
+    weather: String,
+    temp: f64,
+    temp_unit: String,
+    wind_speed: f64,
+    wind_speed_unit: String,
+    sunrise: String,
+    sunset: String,
+}
+
+/// Per-invocation overrides for `thundery.toml`. Anything left unset here falls through
+/// to the persisted config, which itself falls through to `Config::default()`.
+#[derive(Parser)]
+#[command(name = "thundery", about = "A small terminal weather report")]
+struct Cli {
+    #[arg(long)]
+    city: Option<String>,
+    #[arg(long)]
+    units: Option<String>,
+    #[arg(long = "api-key")]
+    api_key: Option<String>,
+    #[arg(long = "time-format")]
+    time_format: Option<String>,
+    #[arg(long = "no-colors")]
+    no_colors: bool,
+    #[arg(long)]
+    format: Option<String>,
+    #[arg(long = "no-autolocate")]
+    no_autolocate: bool,
+}
+
+// Applies CLI flags over an already-loaded config. Merge order is defaults -> file -> CLI.
+// An explicit `--city` is a clear per-invocation location request, so it takes precedence
+// over a persisted `autolocate = true` rather than being silently overridden by it.
+fn apply_cli_overrides(config: &mut Config, cli: Cli) {
+    if let Some(city) = cli.city {
+        config.city = city;
+        config.autolocate = false;
+        config.city_id = None;
+        config.zipcode = None;
+        config.country_code = None;
+        config.coordinates = None;
+    }
+    if cli.no_autolocate {
+        config.autolocate = false;
+    }
+    if let Some(units) = cli.units {
+        config.units = units;
+    }
+    if let Some(api_key) = cli.api_key {
+        config.api_key = api_key;
+    }
+    if let Some(time_format) = cli.time_format {
+        config.timeformat = time_format;
+    }
+    if cli.no_colors {
+        config.use_colors = false;
+    }
+    if let Some(format) = cli.format {
+        config.output_format = format;
+    }
+}
+
+// Coerces a TOML number to f64 whether it was written as a float or an integer literal,
+// matching the coercion serde already does for the strict `toml::from_str::<Config>` path.
+fn toml_value_as_f64(value: &toml::Value) -> Option<f64> {
+    value.as_float().or_else(|| value.as_integer().map(|i| i as f64))
+}
+
+fn read_config() -> Config {
+    let config_path = config_dir().join("thundery.toml");
 
     if !config_path.exists() {
         let default_config = Config::default();
@@ -107,6 +330,40 @@ fn read_config() -> Config {
                     "use_colors" => if let Some(b) = value.as_bool() {
                         default_config.use_colors = b;
                     }
+                    "autolocate" => if let Some(b) = value.as_bool() {
+                        default_config.autolocate = b;
+                    }
+                    "autolocate_interval" => if let Some(i) = value.as_integer() {
+                        default_config.autolocate_interval = i;
+                    }
+                    "city_id" => if let Some(i) = value.as_integer() {
+                        default_config.city_id = Some(i as u64);
+                    }
+                    "zipcode" => if let Some(s) = value.as_str() {
+                        default_config.zipcode = Some(s.to_string());
+                    }
+                    "country_code" => if let Some(s) = value.as_str() {
+                        default_config.country_code = Some(s.to_string());
+                    }
+                    "coordinates" => if let Some(arr) = value.as_array() {
+                        if let [lat, lon] = arr.as_slice() {
+                            if let (Some(lat), Some(lon)) = (toml_value_as_f64(lat), toml_value_as_f64(lon)) {
+                                default_config.coordinates = Some([lat, lon]);
+                            }
+                        }
+                    }
+                    "show_forecast" => if let Some(b) = value.as_bool() {
+                        default_config.show_forecast = b;
+                    }
+                    "output_format" => if let Some(s) = value.as_str() {
+                        default_config.output_format = s.to_string();
+                    }
+                    "lang" => if let Some(s) = value.as_str() {
+                        default_config.lang = s.to_string();
+                    }
+                    "labels" => if let Ok(labels) = value.clone().try_into::<Labels>() {
+                        default_config.labels = labels;
+                    }
                     _ => (),
                 }
             }
@@ -123,12 +380,37 @@ fn read_config() -> Config {
 }
 
 fn main() {
-    let config = read_config();
+    let mut config = read_config();
+    apply_cli_overrides(&mut config, Cli::parse());
+
+    let location = resolve_autolocation(&config);
+
+    // The resolved city (if any) is what's actually being queried, so display that instead
+    // of the possibly stale/empty configured city. When neither autolocate nor `city` gives
+    // us a name (e.g. `city_id`/`zipcode`/`coordinates` specifiers), it's filled in below from
+    // the API response itself.
+    let mut display_city = location
+        .as_ref()
+        .map(|(_, _, city)| city.clone())
+        .unwrap_or_else(|| config.city.clone());
+
+    let location_query = if let Some((latitude, longitude, _)) = location {
+        format!("lat={}&lon={}", latitude, longitude)
+    } else {
+        match build_location_query(&config) {
+            Ok(query) => query,
+            Err(message) => {
+                eprintln!("{}", message);
+                return;
+            }
+        }
+    };
 
     let url = format!(
-        "https://api.openweathermap.org/data/2.5/weather?q={}&units={}&APPID={}",
-        config.city,
+        "https://api.openweathermap.org/data/2.5/weather?{}&units={}&lang={}&APPID={}",
+        location_query,
         config.units,
+        config.lang,
         config.api_key
     );
 
@@ -137,6 +419,12 @@ fn main() {
     if response.status().is_success() {
         let weather_data: Value = response.json().expect("Failed to parse JSON");
 
+        if display_city.is_empty() {
+            if let Some(name) = weather_data["name"].as_str() {
+                display_city = name.to_string();
+            }
+        }
+
         let weather = weather_data["weather"][0]["main"].as_str().unwrap_or("Unknown");
         let temp = weather_data["main"]["temp"].as_f64().unwrap_or(0.0);
         let wind_speed = weather_data["wind"]["speed"].as_f64().unwrap_or(0.0);
@@ -155,16 +443,63 @@ fn main() {
             _ => "K",
         };
 
-        let temp_str = if config.use_colors {
-            format!("Temperature: {:.1}{}", temp, temp_unit).red().to_string()
+        let label_temperature = config.labels.temperature.as_deref().unwrap_or("Temperature");
+        let label_wind_speed = config.labels.wind_speed.as_deref().unwrap_or("Wind speed");
+        let label_sunrise = config.labels.sunrise.as_deref().unwrap_or("Sunrise");
+        let label_sunset = config.labels.sunset.as_deref().unwrap_or("Sunset");
+        let label_city = config.labels.city.as_deref().unwrap_or("City");
+        let label_date = config.labels.date.as_deref().unwrap_or("Date");
+
+        let localized_description = if config.lang != "en" {
+            weather_data["weather"][0]["description"].as_str()
+        } else {
+            None
+        };
+
+        let weather_label = |fallback: &str| -> String {
+            format!("Weather: {}", localized_description.unwrap_or(fallback))
+        };
+
+        // The trend arrow only renders in the "normal" ASCII-art output, so skip the extra
+        // API call (and rate-limit budget) entirely for "clean"/"json" runs.
+        let forecast_temp = if
+            config.show_forecast &&
+            !matches!(config.output_format.as_str(), "clean" | "json")
+        {
+            fetch_forecast_temp(&location_query, &config)
         } else {
-            format!("Temperature: {:.1}{}", temp, temp_unit)
+            None
+        };
+
+        let temp_plain = match forecast_temp {
+            Some(t_next) => {
+                let delta = t_next - temp;
+                let trend_glyph = if delta > 0.5 {
+                    "↗"
+                } else if delta < -0.5 {
+                    "↘"
+                } else {
+                    "→"
+                };
+                format!(
+                    "{}: {:.1}{} {} {:.1}{}",
+                    label_temperature,
+                    temp,
+                    temp_unit,
+                    trend_glyph,
+                    t_next,
+                    temp_unit
+                )
+            }
+            None => format!("{}: {:.1}{}", label_temperature, temp, temp_unit),
         };
 
+        let temp_str = if config.use_colors { temp_plain.red().to_string() } else { temp_plain };
+
         let wind_speed_str = if config.use_colors {
-            format!("Wind speed: {:.1} {}", wind_speed, windspeedunits).cyan().to_string()
+            format!("{}: {:.1} {}", label_wind_speed, wind_speed, windspeedunits).cyan().to_string()
         } else {
-            format!("Wind speed: {:.1} {}", wind_speed, windspeedunits)
+            format!("{}: {:.1} {}", label_wind_speed, wind_speed, windspeedunits)
         };
 
         let sunrise_datetime: DateTime<Utc> = Utc.timestamp_opt(sunrise, 0).unwrap();
@@ -183,6 +518,39 @@ fn main() {
         let sunrisestring = adjusted_sunrise.format(time_format).to_string();
         let sunsetstring = adjusted_sunset.format(time_format).to_string();
 
+        match config.output_format.as_str() {
+            "clean" => {
+                println!(
+                    "{},{},{:.1},{:.1},{},{}",
+                    display_city,
+                    weather,
+                    temp,
+                    wind_speed,
+                    sunrisestring,
+                    sunsetstring
+                );
+                return;
+            }
+            "json" => {
+                let output = WeatherOutput {
+                    city: display_city.clone(),
+                    weather: weather.to_string(),
+                    temp,
+                    temp_unit: temp_unit.to_string(),
+                    wind_speed,
+                    wind_speed_unit: windspeedunits.to_string(),
+                    sunrise: sunrisestring,
+                    sunset: sunsetstring,
+                };
+                let json_string = serde_json
+                    ::to_string(&output)
+                    .expect("Failed to serialize weather output");
+                println!("{}", json_string);
+                return;
+            }
+            _ => (),
+        }
+
         let date = if config.showdate {
             let now = Utc::now();
             now.format("%x").to_string()
@@ -190,7 +558,7 @@ fn main() {
             String::new()
         };
 
-        let date_label = if config.showdate { "Date: " } else { "" };
+        let date_label = if config.showdate { format!("{}: ", label_date) } else { String::new() };
         let date_value = if config.showdate { date } else { String::new() };
 
         let output = match weather {
@@ -204,28 +572,28 @@ fn main() {
    /   \     {}
              {}{}"#,
                     if config.use_colors && config.showcityname {
-                        format!("City: {}", config.city).bold().green().to_string()
+                        format!("{}: {}", label_city, display_city).bold().green().to_string()
                     } else if config.showcityname {
-                        format!("City: {}", config.city).to_string()
+                        format!("{}: {}", label_city, display_city).to_string()
                     } else {
                         String::new()
                     },
                     if config.use_colors {
-                        "Weather: clear".yellow().bold().to_string()
+                        weather_label("clear").yellow().bold().to_string()
                     } else {
-                        "Weather: clear".to_string()
+                        weather_label("clear")
                     },
                     temp_str,
                     wind_speed_str,
                     if config.use_colors {
-                        format!("Sunrise: {sunrisestring}").yellow().to_string()
+                        format!("{}: {}", label_sunrise, sunrisestring).yellow().to_string()
                     } else {
-                        format!("Sunrise: {sunrisestring}")
+                        format!("{}: {}", label_sunrise, sunrisestring)
                     },
                     if config.use_colors {
-                        format!("Sunset: {sunsetstring}").blue().to_string()
+                        format!("{}: {}", label_sunset, sunsetstring).blue().to_string()
                     } else {
-                        format!("Sunset: {sunsetstring}")
+                        format!("{}: {}", label_sunset, sunsetstring)
                     },
                     if config.use_colors {
                         date_label.white().to_string()
@@ -248,28 +616,28 @@ fn main() {
                {}
                {}{}"#,
                     if config.use_colors && config.showcityname {
-                        format!("City: {}", config.city).bold().green().to_string()
+                        format!("{}: {}", label_city, display_city).bold().green().to_string()
                     } else if config.showcityname {
-                        format!("City: {}", config.city).to_string()
+                        format!("{}: {}", label_city, display_city).to_string()
                     } else {
                         String::new()
                     },
                     if config.use_colors {
-                        "Weather: cloudy".bold().magenta().to_string()
+                        weather_label("cloudy").bold().magenta().to_string()
                     } else {
-                        "Weather: cloudy".to_string()
+                        weather_label("cloudy")
                     },
                     temp_str,
                     wind_speed_str,
                     if config.use_colors {
-                        format!("Sunrise: {sunrisestring}").yellow().to_string()
+                        format!("{}: {}", label_sunrise, sunrisestring).yellow().to_string()
                     } else {
-                        format!("Sunrise: {sunrisestring}")
+                        format!("{}: {}", label_sunrise, sunrisestring)
                     },
                     if config.use_colors {
-                        format!("Sunset: {sunsetstring}").blue().to_string()
+                        format!("{}: {}", label_sunset, sunsetstring).blue().to_string()
                     } else {
-                        format!("Sunset: {sunsetstring}")
+                        format!("{}: {}", label_sunset, sunsetstring)
                     },
                     if config.use_colors {
                         date_label.cyan().to_string()
@@ -292,28 +660,28 @@ fn main() {
                {}
                {}{}"#,
                     if config.use_colors && config.showcityname {
-                        format!("City: {}", config.city).bold().green().to_string()
+                        format!("{}: {}", label_city, display_city).bold().green().to_string()
                     } else if config.showcityname {
-                        format!("City: {}", config.city).to_string()
+                        format!("{}: {}", label_city, display_city).to_string()
                     } else {
                         String::new()
                     },
                     if config.use_colors {
-                        "Weather: rainy".bold().blue().to_string()
+                        weather_label("rainy").bold().blue().to_string()
                     } else {
-                        "Weather: rainy".to_string()
+                        weather_label("rainy")
                     },
                     temp_str,
                     wind_speed_str,
                     if config.use_colors {
-                        format!("Sunrise: {sunrisestring}").yellow().to_string()
+                        format!("{}: {}", label_sunrise, sunrisestring).yellow().to_string()
                     } else {
-                        format!("Sunrise: {sunrisestring}")
+                        format!("{}: {}", label_sunrise, sunrisestring)
                     },
                     if config.use_colors {
-                        format!("Sunset: {sunsetstring}").blue().to_string()
+                        format!("{}: {}", label_sunset, sunsetstring).blue().to_string()
                     } else {
-                        format!("Sunset: {sunsetstring}")
+                        format!("{}: {}", label_sunset, sunsetstring)
                     },
                     if config.use_colors {
                         date_label.white().to_string()
@@ -336,28 +704,28 @@ fn main() {
   * * * *      {}
                {}{}"#,
                     if config.use_colors && config.showcityname {
-                        format!("City: {}", config.city).bold().green().to_string()
+                        format!("{}: {}", label_city, display_city).bold().green().to_string()
                     } else if config.showcityname {
-                        format!("City: {}", config.city).to_string()
+                        format!("{}: {}", label_city, display_city).to_string()
                     } else {
                         String::new()
                     },
                     if config.use_colors {
-                        "Weather: snowy".bold().magenta().to_string()
+                        weather_label("snowy").bold().magenta().to_string()
                     } else {
-                        "Weather: snowy".to_string()
+                        weather_label("snowy")
                     },
                     temp_str,
                     wind_speed_str,
                     if config.use_colors {
-                        format!("Sunrise: {sunrisestring}").yellow().to_string()
+                        format!("{}: {}", label_sunrise, sunrisestring).yellow().to_string()
                     } else {
-                        format!("Sunrise: {sunrisestring}")
+                        format!("{}: {}", label_sunrise, sunrisestring)
                     },
                     if config.use_colors {
-                        format!("Sunset: {sunsetstring}").blue().to_string()
+                        format!("{}: {}", label_sunset, sunsetstring).blue().to_string()
                     } else {
-                        format!("Sunset: {sunsetstring}")
+                        format!("{}: {}", label_sunset, sunsetstring)
                     },
                     if config.use_colors {
                         date_label.white().to_string()
@@ -380,28 +748,28 @@ fn main() {
      /  /      {}
                {}{}"#,
                     if config.use_colors && config.showcityname {
-                        format!("City: {}", config.city).bold().green().to_string()
+                        format!("{}: {}", label_city, display_city).bold().green().to_string()
                     } else if config.showcityname {
-                        format!("City: {}", config.city).to_string()
+                        format!("{}: {}", label_city, display_city).to_string()
                     } else {
                         String::new()
                     },
                     if config.use_colors {
-                        "Weather: thundery".bold().black().to_string()
+                        weather_label("thundery").bold().black().to_string()
                     } else {
-                        "Weather: thundery".to_string()
+                        weather_label("thundery")
                     },
                     temp_str,
                     wind_speed_str,
                     if config.use_colors {
-                        format!("Sunrise: {sunrisestring}").yellow().to_string()
+                        format!("{}: {}", label_sunrise, sunrisestring).yellow().to_string()
                     } else {
-                        format!("Sunrise: {sunrisestring}")
+                        format!("{}: {}", label_sunrise, sunrisestring)
                     },
                     if config.use_colors {
-                        format!("Sunset: {sunsetstring}").blue().to_string()
+                        format!("{}: {}", label_sunset, sunsetstring).blue().to_string()
                     } else {
-                        format!("Sunset: {sunsetstring}")
+                        format!("{}: {}", label_sunset, sunsetstring)
                     },
                     if config.use_colors {
                         date_label.white().to_string()
@@ -424,28 +792,28 @@ fn main() {
                {}
                {}{}"#,
                     if config.use_colors && config.showcityname {
-                        format!("City: {}", config.city).bold().green().to_string()
+                        format!("{}: {}", label_city, display_city).bold().green().to_string()
                     } else if config.showcityname {
-                        format!("City: {}", config.city).to_string()
+                        format!("{}: {}", label_city, display_city).to_string()
                     } else {
                         String::new()
                     },
                     if config.use_colors {
-                        format!("Weather: {weather}").bold().red().to_string()
+                        weather_label(weather).bold().red().to_string()
                     } else {
-                        format!("Weather: {weather}")
+                        weather_label(weather)
                     },
                     temp_str,
                     wind_speed_str,
                     if config.use_colors {
-                        format!("Sunrise: {sunrisestring}").yellow().to_string()
+                        format!("{}: {}", label_sunrise, sunrisestring).yellow().to_string()
                     } else {
-                        format!("Sunrise: {sunrisestring}")
+                        format!("{}: {}", label_sunrise, sunrisestring)
                     },
                     if config.use_colors {
-                        format!("Sunset: {sunsetstring}").blue().to_string()
+                        format!("{}: {}", label_sunset, sunsetstring).blue().to_string()
                     } else {
-                        format!("Sunset: {sunsetstring}")
+                        format!("{}: {}", label_sunset, sunsetstring)
                     },
                     if config.use_colors {
                         date_label.white().to_string()